@@ -195,9 +195,14 @@ impl<'a> Encapsulation<'a> {
             .strip_prefix(PRE_ENCAPSULATION_BOUNDARY)
             .ok_or(Error::PreEncapsulationBoundary)?;
 
-        let (label, body) = grammar::split_label(data).ok_or(Error::Label)?;
+        let (label, mut body) = grammar::split_label(data).ok_or(Error::Label)?;
 
-        let mut body = match grammar::strip_trailing_eol(body).unwrap_or(body) {
+        // Tolerate any number of trailing newlines after the post-encapsulation boundary
+        while let Some(stripped) = grammar::strip_trailing_eol(body) {
+            body = stripped;
+        }
+
+        let mut body = match body {
             [head @ .., b'-', b'-', b'-', b'-', b'-'] => head,
             _ => return Err(Error::PreEncapsulationBoundary),
         };
@@ -110,3 +110,17 @@ fn ed25519_example() {
     let label = pem_rfc7468::decode_label(pem).unwrap();
     assert_eq!(label, "ED25519 CERT");
 }
+
+#[test]
+fn trailing_newline_variations() {
+    let pem = include_str!("examples/pkcs8.pem");
+    let pem = pem.trim_end();
+
+    for suffix in ["", "\n", "\r\n", "\n\n", "\r\n\r\n"] {
+        let input = format!("{}{}", pem, suffix);
+        let mut buf = [0u8; 2048];
+        let (label, decoded) = pem_rfc7468::decode(input.as_bytes(), &mut buf).unwrap();
+        assert_eq!(label, "PRIVATE KEY");
+        assert_eq!(decoded, include_bytes!("examples/pkcs8.der"));
+    }
+}